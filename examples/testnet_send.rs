@@ -0,0 +1,80 @@
+//! Sultan L1 testnet end-to-end example - Rust
+//!
+//! Funds a fresh wallet from a funded testnet key, sends part of it on to a
+//! second fresh wallet, waits for confirmation, and checks both balances
+//! moved. Doubles as an integration smoke test for the SDK in
+//! `sdk_example.rs`.
+//!
+//! ## Setup
+//!
+//! Same dependencies as `sdk_example.rs`. The testnet has no faucet
+//! endpoint, so supply the key of an already-funded testnet wallet.
+//!
+//! ## Usage
+//!
+//! ```bash
+//! # Does nothing unless SULTAN_E2E=1, so CI can build it unconditionally
+//! SULTAN_E2E=1 SULTAN_TESTNET_FUNDER_KEY=<hex> cargo run --example testnet_send
+//! ```
+
+#[path = "sdk_example.rs"]
+#[allow(dead_code, unused_imports)]
+mod sdk;
+
+use sdk::{ChainId, ConfirmationPolicy, SultanError, SultanSDK, Wallet};
+use std::time::Duration;
+
+/// Atomic units moved funder -> A and A -> B
+const FUNDING: u128 = 2_000_000_000;
+const TRANSFER: u128 = 1_000_000_000;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    if std::env::var("SULTAN_E2E").as_deref() != Ok("1") {
+        println!("SULTAN_E2E is not 1; skipping the testnet run");
+        return Ok(());
+    }
+    let funder_key = std::env::var("SULTAN_TESTNET_FUNDER_KEY")
+        .map_err(|_| "SULTAN_TESTNET_FUNDER_KEY must hold the hex key of a funded testnet wallet")?;
+
+    let sdk = SultanSDK::builder()
+        .chain(ChainId::Testnet)
+        .timeout(Duration::from_secs(15))
+        .retries(2)
+        .confirmation_policy(ConfirmationPolicy::new(1))
+        .poll_interval(Duration::from_secs(2))
+        .build();
+    let confirm_within = Duration::from_secs(120);
+
+    let funder = Wallet::from_private_key(&funder_key)?;
+    let alice = Wallet::new();
+    let bob = Wallet::new();
+    println!("Funder: {}", funder.address);
+    println!("Alice:  {}", alice.address);
+    println!("Bob:    {}", bob.address);
+
+    match sdk.get_balance(&funder.address).await {
+        Ok(balance) if balance.balance >= FUNDING => {}
+        Ok(balance) => return Err(format!("funder holds only {} atomic units", balance.balance).into()),
+        Err(SultanError::RequestTimeout(_)) => return Err("testnet did not answer in time".into()),
+        Err(e) => return Err(e.into()),
+    }
+
+    let funded = sdk
+        .send_and_confirm_atomic(&funder, &alice.address, FUNDING, confirm_within)
+        .await?;
+    println!("Funded Alice in block {:?} ({})", funded.block_height, funded.hash);
+
+    let sent = sdk
+        .send_and_confirm_atomic(&alice, &bob.address, TRANSFER, confirm_within)
+        .await?;
+    println!("Alice paid Bob in block {:?} ({})", sent.block_height, sent.hash);
+
+    let alice_after = sdk.get_balance(&alice.address).await?.balance;
+    let bob_after = sdk.get_balance(&bob.address).await?.balance;
+    assert_eq!(bob_after, TRANSFER, "Bob's balance");
+    assert_eq!(alice_after, FUNDING - TRANSFER, "Alice's balance (transfers are fee-free)");
+    println!("✅ Balances moved as expected");
+
+    Ok(())
+}